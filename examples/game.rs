@@ -1,5 +1,7 @@
 use bevy::{asset::LoadState, prelude::*};
-use bevy_openal::{efx, Context, GlobalEffects, Listener, OpenAlPlugin, Sound, SoundState};
+use bevy_openal::{
+    efx, Context, GlobalEffects, Listener, OpenAlPlugin, Sound, SoundParams, SoundState,
+};
 
 #[derive(Default)]
 struct AssetHandles {
@@ -41,13 +43,13 @@ fn load_and_create(
         commands
             .spawn()
             .insert(Transform::from_translation(Vec3::new(15., 0., 0.)))
-            .insert(Sound {
+            .insert(Sound(SoundParams {
                 buffer,
                 state: SoundState::Playing,
                 gain: 0.4,
                 looping: true,
                 ..Default::default()
-            });
+            }));
     }
 }
 
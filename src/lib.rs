@@ -1,11 +1,15 @@
 use std::{
+    borrow::Cow,
     collections::HashMap,
     io::Cursor,
     sync::{Arc, Mutex},
 };
 
-pub use alto::{efx, Context, Device, Source};
-use alto::{efx::AuxEffectSlot, Alto, ContextAttrs, Mono, SourceState, StaticSource, Stereo};
+pub use alto::{efx, Context, Device};
+use alto::{
+    efx::AuxEffectSlot, Alto, ContextAttrs, Mono, Source as AlSource, SourceState, StaticSource,
+    Stereo, StreamingSource,
+};
 use bevy::{
     asset::{AssetLoader, HandleId, LoadContext, LoadedAsset},
     prelude::*,
@@ -23,6 +27,70 @@ pub struct Buffer {
     samples: Vec<i16>,
     sample_rate: i32,
     channels: u16,
+    /// Only populated for ogg/mp3, which leave `samples` empty at load time.
+    encoded: Option<EncodedAudio>,
+}
+
+/// The original encoded file plus its extension, kept so a streaming decoder
+/// can be (re-)opened, e.g. to loop a track by decoding from the top again.
+#[derive(Clone, Debug)]
+struct EncodedAudio {
+    bytes: Arc<Vec<u8>>,
+    extension: String,
+}
+
+/// Bound on consecutive recoverable mp3 decode errors before giving up.
+const MP3_MAX_RECOVERABLE_ERRORS: usize = 1024;
+
+/// A decoder held alive for the lifetime of a streaming voice, yielding the
+/// track one chunk at a time instead of buffering it whole.
+enum StreamDecoder {
+    Ogg(OggStreamReader<Cursor<Vec<u8>>>),
+    Mp3(Decoder<Cursor<Vec<u8>>>),
+}
+
+impl StreamDecoder {
+    /// Opens a fresh decoder over `encoded`, or `None` for formats we don't stream.
+    fn open(encoded: &EncodedAudio) -> Option<Self> {
+        let cursor = Cursor::new((*encoded.bytes).clone());
+        match encoded.extension.as_str() {
+            "ogg" => OggStreamReader::new(cursor).ok().map(StreamDecoder::Ogg),
+            "mp3" => Some(StreamDecoder::Mp3(Decoder::new(cursor))),
+            _ => None,
+        }
+    }
+
+    /// Decodes the next chunk of interleaved samples, or `None` at end of stream.
+    fn next_chunk(&mut self) -> Option<Vec<i16>> {
+        match self {
+            StreamDecoder::Ogg(stream) => match stream.read_dec_packet_itl() {
+                Ok(samples) => samples,
+                Err(_) => None,
+            },
+            StreamDecoder::Mp3(decoder) => {
+                // `Eof` ends the stream; other errors (e.g. skipped ID3 frames) just try the next frame.
+                for _ in 0..MP3_MAX_RECOVERABLE_ERRORS {
+                    match decoder.next_frame() {
+                        Ok(frame) => return Some(frame.data),
+                        Err(Error::Eof) => return None,
+                        Err(_) => continue,
+                    }
+                }
+                None
+            }
+        }
+    }
+}
+
+/// Decodes `encoded` to completion, for the one-time upgrade of a
+/// streaming-eligible asset to a whole-track `alto::Buffer`.
+fn decode_all(encoded: &EncodedAudio) -> Option<Vec<i16>> {
+    let mut decoder = StreamDecoder::open(encoded)?;
+    let mut samples = Vec::new();
+    while let Some(mut chunk) = decoder.next_chunk() {
+        samples.append(&mut chunk);
+    }
+    Some(samples)
 }
 
 #[derive(Clone, Copy, Debug, Default)]
@@ -35,9 +103,17 @@ impl AssetLoader for BufferAssetLoader {
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
         Box::pin(async move {
-            let cursor = Cursor::new(bytes.to_vec());
-            let buffer: Option<Buffer> =
-                match load_context.path().extension().unwrap().to_str().unwrap() {
+            let raw = bytes.to_vec();
+            let cursor = Cursor::new(raw.clone());
+            let extension = load_context
+                .path()
+                .extension()
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string();
+            let mut buffer: Option<Buffer> =
+                match extension.as_str() {
                     "flac" => {
                         let reader = claxon::FlacReader::new(cursor);
                         if let Ok(mut reader) = reader {
@@ -50,48 +126,44 @@ impl AssetLoader for BufferAssetLoader {
                                 samples,
                                 sample_rate: info.sample_rate as i32,
                                 channels: info.channels as u16,
+                                encoded: None,
                             })
                         } else {
                             None
                         }
                     }
                     "ogg" => {
-                        let mut stream = OggStreamReader::new(cursor)?;
-                        let mut samples: Vec<i16> = vec![];
-                        while let Some(pck_samples) = &mut stream.read_dec_packet_itl()? {
-                            samples.append(pck_samples);
-                        }
+                        // Read only the header; the full decode happens on demand.
+                        let stream = OggStreamReader::new(cursor)?;
                         Some(Buffer {
-                            samples,
+                            samples: vec![],
                             channels: stream.ident_hdr.audio_channels as u16,
                             sample_rate: stream.ident_hdr.audio_sample_rate as i32,
+                            encoded: None,
                         })
                     }
                     "mp3" => {
+                        // Decode a single frame for its header, then stop.
                         let mut decoder = Decoder::new(cursor);
-                        let mut samples: Vec<i16> = vec![];
                         let mut channels = 0_u16;
                         let mut sample_rate = 0;
-                        let mut succeeded = true;
-                        loop {
+                        for _ in 0..MP3_MAX_RECOVERABLE_ERRORS {
                             match decoder.next_frame() {
-                                Ok(mut frame) => {
-                                    samples.append(&mut frame.data);
+                                Ok(frame) => {
                                     channels = frame.channels as u16;
                                     sample_rate = frame.sample_rate;
-                                }
-                                Err(Error::Eof) => break,
-                                Err(_) => {
-                                    succeeded = false;
                                     break;
                                 }
+                                Err(Error::Eof) => break,
+                                Err(_) => continue,
                             };
                         }
-                        if succeeded {
+                        if channels > 0 {
                             Some(Buffer {
-                                samples,
+                                samples: vec![],
                                 channels,
                                 sample_rate,
+                                encoded: None,
                             })
                         } else {
                             None
@@ -108,6 +180,7 @@ impl AssetLoader for BufferAssetLoader {
                                 samples,
                                 sample_rate: reader.spec().sample_rate as i32,
                                 channels: reader.spec().channels,
+                                encoded: None,
                             })
                         } else {
                             None
@@ -115,6 +188,14 @@ impl AssetLoader for BufferAssetLoader {
                     }
                     _ => None,
                 };
+            if let Some(buffer) = buffer.as_mut() {
+                if matches!(extension.as_str(), "ogg" | "mp3") {
+                    buffer.encoded = Some(EncodedAudio {
+                        bytes: Arc::new(raw),
+                        extension,
+                    });
+                }
+            }
             if let Some(buffer) = buffer {
                 load_context.set_default_asset(LoadedAsset::new(buffer));
             }
@@ -141,18 +222,14 @@ fn buffer_creation(
         match event {
             AssetEvent::Created { handle } => {
                 if let Some(buffer) = assets.get(handle) {
-                    let buffer = match buffer.channels {
-                        1 => {
-                            context.new_buffer::<Mono<i16>, _>(&buffer.samples, buffer.sample_rate)
-                        }
-                        2 => context
-                            .new_buffer::<Stereo<i16>, _>(&buffer.samples, buffer.sample_rate),
-                        _ => {
-                            panic!("Unsupported channel count");
-                        }
-                    };
-                    if let Ok(buffer) = buffer {
-                        buffers.0.insert(handle.id, Arc::new(buffer));
+                    // Streaming-eligible assets are decoded lazily instead, in acquire_sources.
+                    if buffer.encoded.is_some() {
+                        continue;
+                    }
+                    if let Some(al_buffer) =
+                        new_al_buffer(&context, &buffer.samples, buffer.channels, buffer.sample_rate)
+                    {
+                        buffers.0.insert(handle.id, Arc::new(al_buffer));
                     }
                 }
             }
@@ -164,6 +241,31 @@ fn buffer_creation(
     }
 }
 
+/// Runtime-adjustable volume buses, e.g. `"music"` or `"sfx"`; a sound's
+/// category gain is multiplied with `master_gain`.
+#[derive(Clone, Debug)]
+pub struct AudioCategories {
+    pub gains: HashMap<Cow<'static, str>, f32>,
+    pub master_gain: f32,
+}
+
+impl Default for AudioCategories {
+    fn default() -> Self {
+        Self {
+            gains: HashMap::new(),
+            master_gain: 1.,
+        }
+    }
+}
+
+impl AudioCategories {
+    /// The multiplier applied to a sound in `category`: its category gain
+    /// (1.0 when the category has no entry) times the master gain.
+    pub fn gain(&self, category: &str) -> f32 {
+        self.gains.get(category).copied().unwrap_or(1.) * self.master_gain
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Reflect)]
 pub enum SoundState {
     Stopped,
@@ -177,9 +279,24 @@ impl Default for SoundState {
     }
 }
 
-#[derive(Component, Clone, Reflect)]
-#[reflect(Component)]
-pub struct Sound {
+/// How a sound is placed in the world: `Spatial` uses the entity's transform
+/// for position/attenuation, `Generic` is listener-relative (UI, music).
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum SoundInterpretation {
+    Spatial,
+    Generic,
+}
+
+impl Default for SoundInterpretation {
+    fn default() -> Self {
+        SoundInterpretation::Spatial
+    }
+}
+
+/// Generator parameters shared by [`Sound`] and [`StreamingSound`], which
+/// both embed (and deref to) this struct.
+#[derive(Clone, Reflect)]
+pub struct SoundParams {
     pub buffer: Handle<Buffer>,
     pub state: SoundState,
     pub gain: f32,
@@ -190,11 +307,12 @@ pub struct Sound {
     pub rolloff_factor: f32,
     pub radius: f32,
     pub bypass_global_effects: bool,
-    #[reflect(ignore)]
-    pub source: Option<Arc<Mutex<StaticSource>>>,
+    /// [`AudioCategories`] bus this sound is mixed through. Empty means master gain only.
+    pub category: String,
+    pub interpretation: SoundInterpretation,
 }
 
-impl Default for Sound {
+impl Default for SoundParams {
     fn default() -> Self {
         Self {
             buffer: Default::default(),
@@ -207,35 +325,116 @@ impl Default for Sound {
             rolloff_factor: 1.,
             radius: 0.,
             bypass_global_effects: false,
-            source: None,
+            category: String::new(),
+            interpretation: Default::default(),
         }
     }
 }
 
-impl Sound {
+impl SoundParams {
     pub fn stop(&mut self) {
-        if let Some(source) = self.source.as_mut() {
-            let mut source = source.lock().unwrap();
-            source.stop();
-        }
         self.state = SoundState::Stopped;
-        self.source = None;
     }
 
     pub fn play(&mut self) {
-        if let Some(source) = self.source.as_mut() {
-            let mut source = source.lock().unwrap();
-            source.play();
-        }
         self.state = SoundState::Playing;
     }
 
     pub fn pause(&mut self) {
-        if let Some(source) = self.source.as_mut() {
+        self.state = SoundState::Paused;
+    }
+}
+
+/// Describes what to play and how. Paired with a pooled [`Source`] by
+/// `acquire_sources` while playing; does not own an OpenAL voice itself.
+#[derive(Component, Clone, Default, Reflect, Deref, DerefMut)]
+#[reflect(Component)]
+pub struct Sound(pub SoundParams);
+
+/// The OpenAL voice currently driving a [`Sound`]; returned to the
+/// [`SourcePool`] when the voice stops.
+#[derive(Component)]
+pub struct Source {
+    source: Arc<Mutex<StaticSource>>,
+    buffer: HandleId,
+    direct_filter: Option<efx::LowpassFilter>,
+    send_filter: Option<efx::LowpassFilter>,
+    /// Last observed play position, used to detect a loop wrap.
+    last_sample_offset: i32,
+}
+
+/// Emitted as a [`Sound`] or [`StreamingSound`] voice changes phase, so
+/// gameplay doesn't have to poll [`SoundParams::state`] every frame.
+#[derive(Clone, Copy, Debug)]
+pub struct SoundEvent {
+    pub entity: Entity,
+    pub kind: SoundEventKind,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundEventKind {
+    /// A non-looping sound reached the end of its buffer on its own.
+    Finished,
+    /// A looping sound wrapped back to the start (at most once per frame).
+    Looped,
+    /// A sound was stopped (explicitly, or its voice reclaimed) without finishing naturally.
+    Stopped,
+}
+
+/// Muffles a sound with EFX low-pass filters: `direct_*` for the dry path,
+/// `send_*` for the reverb aux sends. Gains are `0.0..=1.0`; `1.0` everywhere is no occlusion.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Occlusion {
+    pub direct_gain: f32,
+    pub direct_gainhf: f32,
+    pub send_gain: f32,
+    pub send_gainhf: f32,
+}
+
+impl Default for Occlusion {
+    fn default() -> Self {
+        Self {
+            direct_gain: 1.,
+            direct_gainhf: 1.,
+            send_gain: 1.,
+            send_gainhf: 1.,
+        }
+    }
+}
+
+/// Entity owning each acquired source, so `reclaim_despawned_sources` can
+/// still pool it if the entity disappears before the `Sound` stops.
+#[derive(Default)]
+struct ActiveSources(HashMap<Entity, Arc<Mutex<StaticSource>>>);
+
+/// A pool of `StaticSource`s reused across `Sound`s that are not
+/// simultaneously audible.
+#[derive(Default)]
+pub struct SourcePool(Vec<Arc<Mutex<StaticSource>>>);
+
+impl SourcePool {
+    /// Hands out an idle source, allocating a fresh one only when the pool is empty.
+    fn acquire(&mut self, context: &Context) -> Option<Arc<Mutex<StaticSource>>> {
+        if let Some(source) = self.0.pop() {
+            Some(source)
+        } else {
+            context
+                .new_static_source()
+                .ok()
+                .map(|source| Arc::new(Mutex::new(source)))
+        }
+    }
+
+    /// Stops a source and returns it to the pool for later reuse.
+    fn release(&mut self, source: Arc<Mutex<StaticSource>>) {
+        {
             let mut source = source.lock().unwrap();
-            source.pause();
+            source.stop();
+            source.clear_buffer().ok();
+            // Leave the next sound to claim this voice with a clean direct path.
+            source.clear_direct_filter();
         }
-        self.state = SoundState::Paused;
+        self.0.push(source);
     }
 }
 
@@ -281,14 +480,163 @@ fn update_listener(
     }
 }
 
+fn acquire_sources(
+    mut commands: Commands,
+    context: Res<Context>,
+    mut buffers: ResMut<Buffers>,
+    assets: Res<Assets<Buffer>>,
+    mut pool: ResMut<SourcePool>,
+    mut active: ResMut<ActiveSources>,
+    query: Query<(Entity, &Sound), Without<Source>>,
+) {
+    for (entity, sound) in query.iter() {
+        if sound.state == SoundState::Stopped {
+            continue;
+        }
+        if !buffers.0.contains_key(&sound.buffer.id) {
+            // First buffered Sound to attach decodes and caches the asset.
+            if let Some(asset) = assets.get(&sound.buffer) {
+                if let Some(encoded) = &asset.encoded {
+                    if let Some(samples) = decode_all(encoded) {
+                        if let Some(al_buffer) =
+                            new_al_buffer(&context, &samples, asset.channels, asset.sample_rate)
+                        {
+                            buffers.0.insert(sound.buffer.id, Arc::new(al_buffer));
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(buffer) = buffers.0.get(&sound.buffer.id) {
+            if let Some(source) = pool.acquire(&context) {
+                {
+                    let mut source = source.lock().unwrap();
+                    source.set_buffer(buffer.clone()).unwrap();
+                }
+                active.0.insert(entity, source.clone());
+                commands.entity(entity).insert(Source {
+                    source,
+                    buffer: sound.buffer.id,
+                    direct_filter: None,
+                    send_filter: None,
+                    last_sample_offset: 0,
+                });
+            }
+        }
+    }
+}
+
+/// Applies `interpretation`'s positioning to `source`: `Spatial` follows the
+/// entity's transform, `Generic` is listener-relative. Shared by the pooled
+/// and streaming update systems so a fix only has to land in one place.
+fn apply_interpretation<S: AlSource>(
+    source: &mut S,
+    interpretation: SoundInterpretation,
+    reference_distance: f32,
+    max_distance: f32,
+    rolloff_factor: f32,
+    radius: f32,
+    transform: Option<&Transform>,
+    global_transform: Option<&GlobalTransform>,
+) {
+    match interpretation {
+        SoundInterpretation::Spatial => {
+            let translation = global_transform
+                .map(|v| v.translation)
+                .or_else(|| transform.map(|v| v.translation));
+            if let Some(translation) = translation {
+                source.set_relative(false);
+                source
+                    .set_position([translation.x, translation.y, translation.z])
+                    .ok();
+            } else {
+                source.set_relative(true);
+                source.set_position([0., 0., 0.]).ok();
+            }
+            source.set_reference_distance(reference_distance).ok();
+            source.set_max_distance(max_distance).ok();
+            source.set_rolloff_factor(rolloff_factor).ok();
+            source.set_radius(radius).ok();
+        }
+        SoundInterpretation::Generic => {
+            source.set_relative(true);
+            source.set_position([0., 0., 0.]).ok();
+        }
+    }
+}
+
+/// Applies (or clears) `occlusion`'s low-pass filters on `source`, caching
+/// them in `direct_filter`/`send_filter`, then routes the global-effect aux
+/// sends (through the send filter when occluded, cleared entirely when
+/// `bypass_global_effects`). Shared by the pooled and streaming update
+/// systems so a fix only has to land in one place.
+fn apply_occlusion_and_sends<S: AlSource>(
+    context: &Context,
+    source: &mut S,
+    occlusion: Option<&Occlusion>,
+    direct_filter: &mut Option<efx::LowpassFilter>,
+    send_filter: &mut Option<efx::LowpassFilter>,
+    bypass_global_effects: bool,
+    global_effects: &mut GlobalEffects,
+) {
+    match occlusion {
+        Some(occlusion) => {
+            if direct_filter.is_none() {
+                *direct_filter = context.new_filter::<efx::LowpassFilter>().ok();
+            }
+            if let Some(filter) = direct_filter.as_mut() {
+                filter.set_gain(occlusion.direct_gain).ok();
+                filter.set_gainhf(occlusion.direct_gainhf).ok();
+                source.set_direct_filter(filter).ok();
+            }
+            if send_filter.is_none() {
+                *send_filter = context.new_filter::<efx::LowpassFilter>().ok();
+            }
+            if let Some(filter) = send_filter.as_mut() {
+                filter.set_gain(occlusion.send_gain).ok();
+                filter.set_gainhf(occlusion.send_gainhf).ok();
+            }
+        }
+        None => {
+            source.clear_direct_filter();
+        }
+    }
+    if bypass_global_effects {
+        // Clear every send so a pooled source doesn't inherit reverb from the sound before it.
+        for (send, _) in global_effects.iter().enumerate() {
+            source.clear_aux_send(send as i32).ok();
+        }
+    } else {
+        for (send, effect) in global_effects.iter_mut().enumerate() {
+            match (occlusion.is_some(), send_filter.as_mut()) {
+                (true, Some(filter)) => {
+                    source.set_aux_send_filter(send as i32, effect, filter).ok();
+                }
+                _ => {
+                    source.set_aux_send(send as i32, effect).ok();
+                }
+            }
+        }
+    }
+}
+
 fn update_source_properties(
     context: Res<Context>,
-    buffers: Res<Buffers>,
     mut global_effects: ResMut<GlobalEffects>,
-    mut query: Query<(&mut Sound, Option<&Transform>, Option<&GlobalTransform>)>,
+    buffers: Res<Buffers>,
+    categories: Res<AudioCategories>,
+    mut query: Query<(
+        &Sound,
+        &mut Source,
+        Option<&Occlusion>,
+        Option<&Transform>,
+        Option<&GlobalTransform>,
+    )>,
 ) {
-    for (mut sound, transform, global_transform) in query.iter_mut() {
-        let Sound {
+    for (sound, mut binding, occlusion, transform, global_transform) in query.iter_mut() {
+        // Reborrow so the source lock and cached filters are disjoint field borrows.
+        let binding = &mut *binding;
+        let SoundParams {
             gain,
             pitch,
             looping,
@@ -297,112 +645,347 @@ fn update_source_properties(
             rolloff_factor,
             radius,
             bypass_global_effects,
-            state,
             ..
-        } = *sound;
-        if state != SoundState::Stopped {
-            let mut swap_buffers = false;
-            if let Some(source) = &sound.source {
-                let source = source.lock().unwrap();
-                if let Some(source_buffer) = source.buffer() {
-                    if let Some(sound_buffer) = buffers.0.get(&sound.buffer.id) {
-                        if source_buffer.as_raw() != sound_buffer.as_raw() {
-                            swap_buffers = true;
-                        }
-                    }
-                }
-            }
-            if swap_buffers {
-                sound.source = None;
-            }
-            if sound.source.is_none() {
-                if let Ok(mut source) = context.new_static_source() {
-                    if let Some(buffer) = buffers.0.get(&sound.buffer.id) {
-                        source.set_buffer(buffer.clone()).unwrap();
-                    }
-                    sound.source = Some(Arc::new(Mutex::new(source)));
-                }
-            }
-            if let Some(source) = sound.source.as_mut() {
-                let mut source = source.lock().unwrap();
-                let translation = global_transform
-                    .map(|v| v.translation)
-                    .or_else(|| transform.map(|v| v.translation));
-                if let Some(translation) = translation {
-                    source.set_relative(false);
-                    source
-                        .set_position([translation.x, translation.y, translation.z])
-                        .ok();
-                } else {
-                    source.set_relative(true);
-                    source.set_position([0., 0., 0.]).ok();
-                }
-                source.set_gain(gain).ok();
-                source.set_pitch(pitch).ok();
-                source.set_looping(looping);
-                source.set_reference_distance(reference_distance).ok();
-                source.set_max_distance(max_distance).ok();
-                source.set_rolloff_factor(rolloff_factor).ok();
-                source.set_radius(radius).ok();
-                if !bypass_global_effects {
-                    for (send, effect) in global_effects.iter_mut().enumerate() {
-                        source.set_aux_send(send as i32, effect).ok();
-                    }
-                }
+        } = sound.0;
+        let mut source = binding.source.lock().unwrap();
+        if binding.buffer != sound.buffer.id {
+            if let Some(buffer) = buffers.0.get(&sound.buffer.id) {
+                source.stop();
+                source.set_buffer(buffer.clone()).unwrap();
+                binding.buffer = sound.buffer.id;
             }
         }
+        apply_interpretation(
+            &mut *source,
+            sound.interpretation,
+            reference_distance,
+            max_distance,
+            rolloff_factor,
+            radius,
+            transform,
+            global_transform,
+        );
+        source.set_gain(gain * categories.gain(&sound.category)).ok();
+        source.set_pitch(pitch).ok();
+        source.set_looping(looping);
+        apply_occlusion_and_sends(
+            &context,
+            &mut *source,
+            occlusion,
+            &mut binding.direct_filter,
+            &mut binding.send_filter,
+            bypass_global_effects,
+            &mut global_effects,
+        );
     }
 }
 
-fn update_source_state(mut query: Query<&mut Sound>) {
-    for mut sound in query.iter_mut() {
-        let mut clear = false;
-        match &sound.state {
-            SoundState::Stopped => {
-                if let Some(source) = sound.source.as_mut() {
-                    let mut source = source.lock().unwrap();
-                    source.stop();
-                }
-                sound.source = None;
-            }
-            SoundState::Playing => {
-                if let Some(source) = sound.source.as_mut() {
-                    let mut source = source.lock().unwrap();
-                    if !vec![
+fn update_source_state(
+    mut commands: Commands,
+    mut pool: ResMut<SourcePool>,
+    mut active: ResMut<ActiveSources>,
+    mut events: EventWriter<SoundEvent>,
+    mut query: Query<(Entity, &mut Sound, &mut Source)>,
+) {
+    for (entity, mut sound, mut binding) in query.iter_mut() {
+        let binding = &mut *binding;
+        let mut release = false;
+        let mut finished = false;
+        {
+            let mut source = binding.source.lock().unwrap();
+            match sound.state {
+                SoundState::Stopped => release = true,
+                SoundState::Playing => {
+                    if ![
                         SourceState::Initial,
                         SourceState::Playing,
                         SourceState::Paused,
                     ]
                     .contains(&source.state())
                     {
-                        clear = true;
-                    } else if source.state() != SourceState::Playing {
-                        source.play();
+                        release = true;
+                        finished = true;
+                    } else {
+                        if source.state() != SourceState::Playing {
+                            source.play();
+                        }
+                        if sound.looping {
+                            let offset = source.sample_offset();
+                            if offset < binding.last_sample_offset {
+                                events.send(SoundEvent {
+                                    entity,
+                                    kind: SoundEventKind::Looped,
+                                });
+                            }
+                            binding.last_sample_offset = offset;
+                        }
                     }
                 }
-            }
-            SoundState::Paused => {
-                if let Some(source) = sound.source.as_mut() {
-                    let mut source = source.lock().unwrap();
+                SoundState::Paused => {
                     if source.state() != SourceState::Paused {
                         source.pause();
                     }
                 }
             }
+            if !release {
+                sound.state = match source.state() {
+                    SourceState::Initial => SoundState::Stopped,
+                    SourceState::Playing => SoundState::Playing,
+                    SourceState::Paused => SoundState::Paused,
+                    SourceState::Stopped => SoundState::Stopped,
+                    SourceState::Unknown(_) => SoundState::Stopped,
+                };
+            }
         }
-        if clear {
-            sound.source = None;
+        if release {
+            pool.release(binding.source.clone());
+            active.0.remove(&entity);
+            commands.entity(entity).remove::<Source>();
+            let kind = if finished && !sound.looping {
+                SoundEventKind::Finished
+            } else {
+                SoundEventKind::Stopped
+            };
             sound.state = SoundState::Stopped;
+            events.send(SoundEvent { entity, kind });
         }
-        if let Some(source) = sound.source.clone() {
-            let source = source.lock().unwrap();
-            sound.state = match &source.state() {
-                SourceState::Initial => SoundState::Stopped,
-                SourceState::Playing => SoundState::Playing,
-                SourceState::Paused => SoundState::Paused,
-                SourceState::Stopped => SoundState::Stopped,
-                SourceState::Unknown(_) => SoundState::Stopped,
-            };
+    }
+}
+
+/// Returns a source to the pool if its entity disappeared without going
+/// through `update_source_state`'s normal release path (e.g. a despawn).
+fn reclaim_despawned_sources(
+    mut removed: RemovedComponents<Source>,
+    mut active: ResMut<ActiveSources>,
+    mut pool: ResMut<SourcePool>,
+    mut events: EventWriter<SoundEvent>,
+) {
+    for entity in removed.iter() {
+        if let Some(source) = active.0.remove(&entity) {
+            pool.release(source);
+            events.send(SoundEvent {
+                entity,
+                kind: SoundEventKind::Stopped,
+            });
+        }
+    }
+}
+
+/// Number of short buffers kept queued on a streaming source at once.
+const STREAMING_BUFFERS: i32 = 4;
+
+/// Like [`Sound`], but decoded incrementally into a short queue of small,
+/// freshly allocated buffers. The referenced [`Buffer`] must carry [`EncodedAudio`] (ogg/mp3).
+#[derive(Component, Clone, Default, Deref, DerefMut)]
+pub struct StreamingSound(pub SoundParams);
+
+/// The streaming OpenAL voice driving a [`StreamingSound`].
+#[derive(Component)]
+struct StreamingVoice {
+    source: Arc<Mutex<StreamingSource>>,
+    decoder: StreamDecoder,
+    encoded: EncodedAudio,
+    channels: u16,
+    sample_rate: i32,
+    /// Set once a non-looping stream hits EOF; queued buffers drain before the sound is set
+    /// to `Stopped` and `Finished` is emitted.
+    draining: bool,
+    direct_filter: Option<efx::LowpassFilter>,
+    send_filter: Option<efx::LowpassFilter>,
+    /// Set by `next_chunk` on a loop wrap, cleared once `Looped` is emitted.
+    looped: bool,
+}
+
+impl StreamingVoice {
+    /// Decodes the next chunk, re-opening the decoder at EOF for a looping stream.
+    fn next_chunk(&mut self, looping: bool) -> Option<Vec<i16>> {
+        if let Some(chunk) = self.decoder.next_chunk() {
+            return Some(chunk);
+        }
+        if looping {
+            if let Some(decoder) = StreamDecoder::open(&self.encoded) {
+                self.decoder = decoder;
+                self.looped = true;
+                return self.decoder.next_chunk();
+            }
+        }
+        None
+    }
+}
+
+/// Uploads interleaved samples to a new hardware buffer.
+fn new_al_buffer(
+    context: &Context,
+    samples: &[i16],
+    channels: u16,
+    sample_rate: i32,
+) -> Option<alto::Buffer> {
+    match channels {
+        1 => context
+            .new_buffer::<Mono<i16>, _>(samples, sample_rate)
+            .ok(),
+        2 => context
+            .new_buffer::<Stereo<i16>, _>(samples, sample_rate)
+            .ok(),
+        _ => None,
+    }
+}
+
+/// Tops the source's queue back up to [`STREAMING_BUFFERS`].
+fn refill_stream(voice: &mut StreamingVoice, looping: bool, context: &Context) {
+    loop {
+        if voice.draining {
+            break;
+        }
+        let queued = voice.source.lock().unwrap().buffers_queued();
+        if queued >= STREAMING_BUFFERS {
+            break;
+        }
+        match voice.next_chunk(looping) {
+            Some(chunk) => {
+                if let Some(buffer) =
+                    new_al_buffer(context, &chunk, voice.channels, voice.sample_rate)
+                {
+                    voice.source.lock().unwrap().queue_buffer(buffer).ok();
+                }
+            }
+            None => {
+                voice.draining = true;
+                break;
+            }
+        }
+    }
+}
+
+fn update_streaming_sources(
+    mut commands: Commands,
+    context: Res<Context>,
+    assets: Res<Assets<Buffer>>,
+    categories: Res<AudioCategories>,
+    mut global_effects: ResMut<GlobalEffects>,
+    mut events: EventWriter<SoundEvent>,
+    mut query: Query<(
+        Entity,
+        &mut StreamingSound,
+        Option<&mut StreamingVoice>,
+        Option<&Occlusion>,
+        Option<&Transform>,
+        Option<&GlobalTransform>,
+    )>,
+) {
+    for (entity, mut sound, voice, occlusion, transform, global_transform) in query.iter_mut() {
+        if sound.state == SoundState::Stopped {
+            // Still present here means stopped externally, not by draining out below.
+            if voice.is_some() {
+                commands.entity(entity).remove::<StreamingVoice>();
+                events.send(SoundEvent {
+                    entity,
+                    kind: SoundEventKind::Stopped,
+                });
+            }
+            continue;
+        }
+        let mut voice = match voice {
+            Some(voice) => voice,
+            None => {
+                // Acquire a streaming voice the first time the sound plays.
+                let encoded = assets.get(&sound.buffer).and_then(|a| a.encoded.clone());
+                let spec = assets.get(&sound.buffer).map(|a| (a.channels, a.sample_rate));
+                if let (Some(encoded), Some((channels, sample_rate))) = (encoded, spec) {
+                    if let Some(decoder) = StreamDecoder::open(&encoded) {
+                        if let Ok(source) = context.new_streaming_source() {
+                            let mut voice = StreamingVoice {
+                                source: Arc::new(Mutex::new(source)),
+                                decoder,
+                                encoded,
+                                channels,
+                                sample_rate,
+                                draining: false,
+                                direct_filter: None,
+                                send_filter: None,
+                                looped: false,
+                            };
+                            refill_stream(&mut voice, sound.looping, &context);
+                            commands.entity(entity).insert(voice);
+                        }
+                    }
+                }
+                continue;
+            }
+        };
+        // Unqueue and drop finished buffers; refill_stream decodes into newly allocated
+        // buffers to replace them (processed buffers are not reused).
+        {
+            let mut source = voice.source.lock().unwrap();
+            let processed = source.buffers_processed();
+            for _ in 0..processed {
+                source.unqueue_buffer().ok();
+            }
+        }
+        refill_stream(&mut voice, sound.looping, &context);
+        if voice.looped {
+            voice.looped = false;
+            events.send(SoundEvent {
+                entity,
+                kind: SoundEventKind::Looped,
+            });
+        }
+        let SoundParams {
+            gain,
+            pitch,
+            reference_distance,
+            max_distance,
+            rolloff_factor,
+            radius,
+            bypass_global_effects,
+            ..
+        } = sound.0;
+        // Reborrow so the source lock and cached filters are disjoint field borrows.
+        let voice = &mut *voice;
+        let mut source = voice.source.lock().unwrap();
+        apply_interpretation(
+            &mut *source,
+            sound.interpretation,
+            reference_distance,
+            max_distance,
+            rolloff_factor,
+            radius,
+            transform,
+            global_transform,
+        );
+        source.set_gain(gain * categories.gain(&sound.category)).ok();
+        source.set_pitch(pitch).ok();
+        apply_occlusion_and_sends(
+            &context,
+            &mut *source,
+            occlusion,
+            &mut voice.direct_filter,
+            &mut voice.send_filter,
+            bypass_global_effects,
+            &mut global_effects,
+        );
+        match sound.state {
+            SoundState::Playing => {
+                // Resume after an underrun as long as there is still audio queued.
+                if source.state() != SourceState::Playing && source.buffers_queued() > 0 {
+                    source.play();
+                }
+                if voice.draining && source.buffers_queued() == 0 {
+                    sound.state = SoundState::Stopped;
+                    drop(source);
+                    commands.entity(entity).remove::<StreamingVoice>();
+                    events.send(SoundEvent {
+                        entity,
+                        kind: SoundEventKind::Finished,
+                    });
+                }
+            }
+            SoundState::Paused => {
+                if source.state() != SourceState::Paused {
+                    source.pause();
+                }
+            }
+            SoundState::Stopped => {}
         }
     }
 }
@@ -417,8 +1000,11 @@ pub struct OpenAlPlugin;
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, SystemLabel)]
 pub enum OpenAlSystem {
     UpdateListener,
+    AcquireSources,
     UpdateSourceProperties,
+    UpdateStreamingSources,
     UpdateSourceState,
+    ReclaimDespawnedSources,
 }
 
 impl Plugin for OpenAlPlugin {
@@ -437,10 +1023,14 @@ impl Plugin for OpenAlPlugin {
             .new_context(Some(context_attrs))
             .expect("Could not create context");
         app.add_asset::<Buffer>()
+            .add_event::<SoundEvent>()
             .init_asset_loader::<BufferAssetLoader>()
             .insert_non_send_resource(device)
             .insert_resource(context)
             .insert_resource(Buffers::default())
+            .insert_resource(SourcePool::default())
+            .insert_resource(ActiveSources::default())
+            .insert_resource(AudioCategories::default())
             .insert_resource(GlobalEffects::default())
             .register_type::<Listener>()
             .add_system(buffer_creation)
@@ -451,16 +1041,36 @@ impl Plugin for OpenAlPlugin {
                     .after(TransformSystem::TransformPropagate)
                     .before(OpenAlSystem::UpdateSourceState),
             )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                acquire_sources
+                    .label(OpenAlSystem::AcquireSources)
+                    .after(TransformSystem::TransformPropagate)
+                    .before(OpenAlSystem::UpdateSourceProperties),
+            )
             .add_system_to_stage(
                 CoreStage::PostUpdate,
                 update_source_properties
                     .label(OpenAlSystem::UpdateSourceProperties)
+                    .after(OpenAlSystem::AcquireSources)
+                    .before(OpenAlSystem::UpdateSourceState),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                update_streaming_sources
+                    .label(OpenAlSystem::UpdateStreamingSources)
                     .after(TransformSystem::TransformPropagate)
                     .before(OpenAlSystem::UpdateSourceState),
             )
             .add_system_to_stage(
                 CoreStage::PostUpdate,
                 update_source_state.label(OpenAlSystem::UpdateSourceState),
+            )
+            .add_system_to_stage(
+                CoreStage::PostUpdate,
+                reclaim_despawned_sources
+                    .label(OpenAlSystem::ReclaimDespawnedSources)
+                    .after(OpenAlSystem::UpdateSourceState),
             );
     }
 }